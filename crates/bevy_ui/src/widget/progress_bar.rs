@@ -1,12 +1,19 @@
-use std::ops::{AddAssign, Deref};
+use std::ops::{AddAssign, Deref, RangeInclusive};
 
-use bevy_ecs::prelude::Component;
-use bevy_ecs::query::Changed;
+use bevy_asset::Handle;
+use bevy_ecs::prelude::{Commands, Component, Entity};
+use bevy_ecs::query::{Added, Changed, Or, With, Without};
 use bevy_ecs::reflect::ReflectComponent;
-use bevy_ecs::system::Query;
+use bevy_ecs::system::{Query, Res};
+use bevy_hierarchy::{BuildChildren, Children};
+use bevy_math::Size;
 use bevy_reflect::Reflect;
+use bevy_render::texture::Image;
+use bevy_text::{HorizontalAlign, Text, TextAlignment, TextStyle, VerticalAlign};
+use bevy_time::Time;
 
-use crate::{Style, Val};
+use crate::entity::{NodeBundle, TextBundle};
+use crate::{PositionType, Style, UiColor, UiImage, Val};
 
 /// Progress struct for ProgressBar.
 /// ```
@@ -17,24 +24,36 @@ use crate::{Style, Val};
 ///
 /// *progress_bar += 50.0;
 ///
-/// let progress_bar_width = Val::Percent(*progress_bar);
+/// let progress_bar_width = Val::Percent(progress_bar.fraction() * 100.0);
 /// ```
 ///
-/// Note: values will be clamped between 0.0 and 100.0
-#[derive(Component, Debug, Default, Clone, Copy, PartialEq, Reflect)]
+/// Note: the value is clamped to `range`, which defaults to `0.0..=100.0`. Use
+/// [`Progress::with_range`] to track values outside that scale (e.g. current/max HP) and
+/// [`Progress::with_step`] to quantize it to fixed increments.
+#[derive(Component, Debug, Clone, PartialEq, Reflect)]
 #[reflect(Component)]
-pub struct Progress(f32);
+pub struct Progress {
+    value: f32,
+    range: RangeInclusive<f32>,
+    step: Option<f32>,
+}
 
 impl Deref for Progress {
     type Target = f32;
 
     fn deref(&self) -> &Self::Target {
-        &self.0
+        &self.value
+    }
+}
+
+impl Default for Progress {
+    fn default() -> Self {
+        Self::new(0.0)
     }
 }
 
 impl Progress {
-    /// Creates a new instance of [`Progress`]
+    /// Creates a new instance of [`Progress`] on the default `0.0..=100.0` scale
     /// ```
     /// # use bevy::prelude::Progress;
     ///
@@ -42,7 +61,13 @@ impl Progress {
     /// let progress_bar = Progress::new(100.0);
     /// ```
     pub fn new(value: f32) -> Self {
-        Progress(Self::clamp_value(value))
+        let mut progress = Progress {
+            value: 0.0,
+            range: 0.0..=100.0,
+            step: None,
+        };
+        progress.set(value);
+        progress
     }
 
     /// Creates a new instance of [`Progress`] with 0% done
@@ -56,11 +81,72 @@ impl Progress {
         Self::new(0.0)
     }
 
+    /// Sets the bounds this [`Progress`] is tracked against, re-clamping the current value
+    ///
+    /// The bounds are normalized (swapped if given in reverse order), so an accidentally
+    /// flipped range clamps sanely instead of panicking.
+    /// ```
+    /// # use bevy::prelude::Progress;
+    ///
+    /// // 137/250 HP (`Progress::new(137.0)` would already clamp to 100.0 on the default scale)
+    /// let mut health_bar = Progress::default().with_range(0.0..=250.0);
+    /// health_bar.set(137.0);
+    /// ```
+    pub fn with_range(mut self, range: RangeInclusive<f32>) -> Self {
+        let (start, end) = (*range.start(), *range.end());
+        self.range = start.min(end)..=start.max(end);
+        self.set(self.value);
+        self
+    }
+
+    /// Quantizes this [`Progress`] to increments of `step`, re-clamping the current value
+    ///
+    /// The sign of `step` doesn't matter; it's stored as a magnitude.
+    /// ```
+    /// # use bevy::prelude::Progress;
+    ///
+    /// // snaps to 0.0, 25.0, 50.0, 75.0 or 100.0
+    /// let stepped_progress_bar = Progress::new(60.0).with_step(25.0);
+    /// ```
+    pub fn with_step(mut self, step: f32) -> Self {
+        self.step = Some(step.abs());
+        self.set(self.value);
+        self
+    }
+
     pub fn set(&mut self, value: f32) {
-        self.0 = Self::clamp_value(value)
+        let min = *self.range.start();
+        let max = *self.range.end();
+        let mut value = value.clamp(min, max);
+        if let Some(step) = self.step {
+            if step > 0.0 {
+                let steps = ((value - min) / step).round();
+                value = (min + steps * step).clamp(min, max);
+            }
+        }
+        self.value = value;
     }
 
-    /// Check if this [`Progress`] has reached 100%
+    /// Returns the current value normalized to a `0.0..=1.0` fraction of `range`
+    /// ```
+    /// # use bevy::prelude::Progress;
+    ///
+    /// let mut progress_bar = Progress::default().with_range(0.0..=250.0);
+    /// progress_bar.set(137.0);
+    /// assert_eq!(progress_bar.fraction(), 137.0 / 250.0);
+    /// ```
+    pub fn fraction(&self) -> f32 {
+        let min = *self.range.start();
+        let max = *self.range.end();
+        let span = max - min;
+        if span <= 0.0 {
+            0.0
+        } else {
+            (self.value - min) / span
+        }
+    }
+
+    /// Check if this [`Progress`] has reached the end of its range
     /// ```
     /// # use bevy::prelude::Progress;
     ///
@@ -71,11 +157,17 @@ impl Progress {
     /// assert!(empty_progress_bar.is_done());
     /// ```
     pub fn is_done(&self) -> bool {
-        (self.0 - 100.0).abs() < f32::EPSILON
+        (self.value - self.range.end()).abs() < f32::EPSILON
+    }
+
+    /// The lower bound of `range`
+    pub fn min(&self) -> f32 {
+        *self.range.start()
     }
 
-    fn clamp_value(value: f32) -> f32 {
-        value.clamp(0.0, 100.0)
+    /// The upper bound of `range`
+    pub fn max(&self) -> f32 {
+        *self.range.end()
     }
 }
 
@@ -88,13 +180,13 @@ impl AddAssign<f32> for Progress {
 /// Specifies progress bar's animation
 #[derive(Component, Debug, Clone, Copy, PartialEq)]
 pub enum ProgressBarAnimation {
-    /// The width of a node will be changed to [`Val::Percent`]\(*progress)
+    /// The width of a node will be changed to [`Val::Percent`]\(fraction * 100)
     /// when the [`Progress`] changes
     ResizeWidth,
-    /// The height of a node will be changed to [`Val::Percent`]\(*progress)
+    /// The height of a node will be changed to [`Val::Percent`]\(fraction * 100)
     /// when the [`Progress`] changes
     ResizeHeight,
-    /// Both the width and the height of a node will be changed to [`Val::Percent`]\(*progress)
+    /// Both the width and the height of a node will be changed to [`Val::Percent`]\(fraction * 100)
     /// when the [`Progress`] changes
     ResizeBothDimensions,
     /// A node's size won't be changed when the [`Progress`] changes. Use this if you want to
@@ -131,7 +223,7 @@ pub enum ProgressBarAnimation {
     ///     for (progress, mut color) in query.iter_mut() {
     ///         // change hue from 0 to 100 (from red to green)
     ///         color.0 = Color::Hsla {
-    ///             hue: *progress,
+    ///             hue: progress.fraction() * 100.0,
     ///             saturation: 0.7,
     ///             lightness: 0.5,
     ///             alpha: 1.0,
@@ -150,20 +242,534 @@ impl Default for ProgressBarAnimation {
 
 /// Updates progress bar [`Size`] if [`Progress`] has changed
 pub fn progress_bar_animation_system(
-    mut query: Query<(&Progress, &ProgressBarAnimation, &mut Style), Changed<Progress>>,
+    mut query: Query<
+        (
+            &Progress,
+            &ProgressBarAnimation,
+            Option<&ProgressBarDirection>,
+            &mut Style,
+        ),
+        (
+            Or<(Changed<Progress>, Changed<ProgressBarDirection>)>,
+            Without<ProgressBarStyle>,
+            Without<ProgressBarIndeterminate>,
+        ),
+    >,
 ) {
-    for (progress, dimension, mut style) in query.iter_mut() {
+    for (progress, dimension, direction, mut style) in query.iter_mut() {
         let (resize_width, resize_height) = match dimension {
             ProgressBarAnimation::ResizeWidth => (true, false),
             ProgressBarAnimation::ResizeHeight => (false, true),
             ProgressBarAnimation::ResizeBothDimensions => (true, true),
             ProgressBarAnimation::Custom => (false, false),
         };
+        let percent = Val::Percent(progress.fraction() * 100.0);
         if resize_width {
-            style.size.width = Val::Percent(**progress);
+            style.size.width = percent;
         }
         if resize_height {
-            style.size.height = Val::Percent(**progress);
+            style.size.height = percent;
+        }
+        let direction = direction.copied().unwrap_or_default();
+        anchor_in_flow(&mut style, direction, resize_width, resize_height);
+    }
+}
+
+/// Specifies which edge a progress bar's fill grows from
+///
+/// [`progress_bar_animation_system`] resizes a node via [`Style::size`], which on its own grows
+/// it from its top-left corner; [`anchor_in_flow`] anchors it to the opposite edge instead (via
+/// an auto margin, since the bar is the caller's own entity and must stay exactly where it
+/// was placed in their layout) so [`ProgressBarDirection::RightToLeft`] and
+/// [`ProgressBarDirection::BottomToTop`] bars fill from the correct side.
+/// [`progress_bar_foreground_system`] anchors its always-absolute foreground child the same
+/// way, but via [`Style::position`] instead, since that child never participates in anyone
+/// else's layout.
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq, Reflect)]
+#[reflect(Component)]
+pub enum ProgressBarDirection {
+    /// The fill grows rightward from the left edge (the default)
+    LeftToRight,
+    /// The fill grows leftward from the right edge
+    RightToLeft,
+    /// The fill grows upward from the bottom edge
+    BottomToTop,
+    /// The fill grows downward from the top edge
+    TopToBottom,
+}
+
+impl Default for ProgressBarDirection {
+    fn default() -> Self {
+        ProgressBarDirection::LeftToRight
+    }
+}
+
+/// True when the fill should originate from the bottom/right edge instead of top/left
+fn is_reversed(direction: ProgressBarDirection) -> bool {
+    matches!(
+        direction,
+        ProgressBarDirection::RightToLeft | ProgressBarDirection::BottomToTop
+    )
+}
+
+/// Anchors an always-[`PositionType::Absolute`] child (e.g. [`ProgressBarForeground`]) to the
+/// edge its fill should originate from via [`Style::position`], explicitly clearing the
+/// opposite edge so that flipping the direction back doesn't leave a stale anchor behind.
+/// Only ever called on a node whose `position_type` is permanently `Absolute`, set once at
+/// spawn — it never toggles the node's own flow membership.
+fn anchor_absolute(
+    style: &mut Style,
+    direction: ProgressBarDirection,
+    resize_width: bool,
+    resize_height: bool,
+) {
+    let reversed = is_reversed(direction);
+    if resize_width {
+        if reversed {
+            style.position.left = Val::Undefined;
+            style.position.right = Val::Px(0.0);
+        } else {
+            style.position.right = Val::Undefined;
+            style.position.left = Val::Px(0.0);
+        }
+    }
+    if resize_height {
+        if reversed {
+            style.position.top = Val::Undefined;
+            style.position.bottom = Val::Px(0.0);
+        } else {
+            style.position.bottom = Val::Undefined;
+            style.position.top = Val::Px(0.0);
+        }
+    }
+}
+
+/// Anchors a resized node to the edge its fill should originate from using an auto margin on
+/// the non-growth side, explicitly clearing the opposite margin so that flipping the
+/// direction back doesn't leave a stale anchor behind. Never touches
+/// [`Style::position_type`], so the node's membership in its parent's flow — and therefore
+/// its siblings' layout — is unaffected by the direction it's given.
+fn anchor_in_flow(
+    style: &mut Style,
+    direction: ProgressBarDirection,
+    resize_width: bool,
+    resize_height: bool,
+) {
+    let reversed = is_reversed(direction);
+    if resize_width {
+        if reversed {
+            style.margin.left = Val::Auto;
+            style.margin.right = Val::Undefined;
+        } else {
+            style.margin.right = Val::Auto;
+            style.margin.left = Val::Undefined;
+        }
+    }
+    if resize_height {
+        if reversed {
+            style.margin.top = Val::Auto;
+            style.margin.bottom = Val::Undefined;
+        } else {
+            style.margin.bottom = Val::Auto;
+            style.margin.top = Val::Undefined;
+        }
+    }
+}
+
+/// Describes the two-layer track-plus-fill look for a progress bar: a `background` node
+/// that always fills its parent, and a `foreground` node that is resized to
+/// [`Progress::fraction`] by [`progress_bar_foreground_system`].
+///
+/// Insert this alongside [`Progress`] and [`ProgressBarAnimation`] and
+/// [`progress_bar_layers_system`] will spawn the two children for you; without it, a
+/// progress bar is just the single node that [`progress_bar_animation_system`] resizes.
+#[derive(Component, Debug, Clone, PartialEq, Reflect)]
+#[reflect(Component)]
+pub struct ProgressBarStyle {
+    pub background_color: UiColor,
+    pub background_image: Option<Handle<Image>>,
+    pub foreground_color: UiColor,
+    pub foreground_image: Option<Handle<Image>>,
+}
+
+/// Marks the background track child spawned by [`progress_bar_layers_system`]
+#[derive(Component, Debug, Default, Clone, Copy, PartialEq, Eq, Reflect)]
+#[reflect(Component)]
+pub struct ProgressBarBackground;
+
+/// Marks the foreground fill child spawned by [`progress_bar_layers_system`]
+#[derive(Component, Debug, Default, Clone, Copy, PartialEq, Eq, Reflect)]
+#[reflect(Component)]
+pub struct ProgressBarForeground;
+
+/// Spawns the background/foreground children described by a newly-added [`ProgressBarStyle`],
+/// sizing the foreground child to the bar's current [`Progress::fraction`] right away. This
+/// can't be left for [`progress_bar_foreground_system`] to pick up via `Added<ProgressBarStyle>`
+/// next frame: the children spawned here aren't visible in anyone else's `&Children` query
+/// until this system's commands are flushed, so a bar that already has a non-zero value when
+/// [`ProgressBarStyle`] is added would otherwise render pinned at 0% until `Progress` next
+/// changes.
+pub fn progress_bar_layers_system(
+    mut commands: Commands,
+    query: Query<
+        (
+            Entity,
+            &ProgressBarStyle,
+            &Progress,
+            &ProgressBarAnimation,
+            Option<&ProgressBarDirection>,
+        ),
+        Added<ProgressBarStyle>,
+    >,
+) {
+    for (entity, bar_style, progress, dimension, direction) in query.iter() {
+        let (resize_width, resize_height) = match dimension {
+            ProgressBarAnimation::ResizeWidth => (true, false),
+            ProgressBarAnimation::ResizeHeight => (false, true),
+            ProgressBarAnimation::ResizeBothDimensions => (true, true),
+            ProgressBarAnimation::Custom => (false, false),
+        };
+        let percent = Val::Percent(progress.fraction() * 100.0);
+        let direction = direction.copied().unwrap_or_default();
+        let mut foreground_style = Style {
+            size: Size::new(
+                if resize_width { percent } else { Val::Percent(100.0) },
+                if resize_height { percent } else { Val::Percent(100.0) },
+            ),
+            position_type: PositionType::Absolute,
+            ..Default::default()
+        };
+        anchor_absolute(&mut foreground_style, direction, resize_width, resize_height);
+
+        commands.entity(entity).with_children(|parent| {
+            parent
+                .spawn_bundle(NodeBundle {
+                    style: Style {
+                        size: Size::new(Val::Percent(100.0), Val::Percent(100.0)),
+                        ..Default::default()
+                    },
+                    color: bar_style.background_color,
+                    image: bar_style
+                        .background_image
+                        .clone()
+                        .map(UiImage)
+                        .unwrap_or_default(),
+                    ..Default::default()
+                })
+                .insert(ProgressBarBackground);
+
+            parent
+                .spawn_bundle(NodeBundle {
+                    style: foreground_style,
+                    color: bar_style.foreground_color,
+                    image: bar_style
+                        .foreground_image
+                        .clone()
+                        .map(UiImage)
+                        .unwrap_or_default(),
+                    ..Default::default()
+                })
+                .insert(ProgressBarForeground);
+        });
+    }
+}
+
+/// Resizes the foreground child to [`Progress::fraction`] of the parent while the
+/// background child is left alone (and so stays full-size). The initial size is set by
+/// [`progress_bar_layers_system`] when the children are spawned; this system only needs to
+/// react to later changes.
+pub fn progress_bar_foreground_system(
+    bars: Query<
+        (
+            &Progress,
+            &ProgressBarAnimation,
+            Option<&ProgressBarDirection>,
+            &Children,
+        ),
+        (
+            With<ProgressBarStyle>,
+            Without<ProgressBarIndeterminate>,
+            Or<(Changed<Progress>, Changed<ProgressBarDirection>)>,
+        ),
+    >,
+    mut foregrounds: Query<&mut Style, With<ProgressBarForeground>>,
+) {
+    for (progress, dimension, direction, children) in bars.iter() {
+        let (resize_width, resize_height) = match dimension {
+            ProgressBarAnimation::ResizeWidth => (true, false),
+            ProgressBarAnimation::ResizeHeight => (false, true),
+            ProgressBarAnimation::ResizeBothDimensions => (true, true),
+            ProgressBarAnimation::Custom => (false, false),
+        };
+        let percent = Val::Percent(progress.fraction() * 100.0);
+        let direction = direction.copied().unwrap_or_default();
+        for &child in children.iter() {
+            if let Ok(mut style) = foregrounds.get_mut(child) {
+                if resize_width {
+                    style.size.width = percent;
+                }
+                if resize_height {
+                    style.size.height = percent;
+                }
+                anchor_absolute(&mut style, direction, resize_width, resize_height);
+            }
+        }
+    }
+}
+
+/// Specifies the label rendered over a progress bar by [`progress_bar_text_system`]
+#[derive(Component, Debug, Clone, PartialEq, Reflect)]
+#[reflect(Component)]
+pub enum ProgressBarText {
+    /// Auto-formatted `"{:.0}%"` of [`Progress::fraction`]
+    Percentage,
+    /// Auto-formatted `"{current}/{max}"` using the bar's configured range
+    Value,
+    /// A fixed label that ignores [`Progress`] entirely
+    Custom(String),
+}
+
+impl ProgressBarText {
+    fn format(&self, progress: &Progress) -> String {
+        match self {
+            ProgressBarText::Percentage => format!("{:.0}%", progress.fraction() * 100.0),
+            ProgressBarText::Value => format!("{:.0}/{:.0}", **progress, progress.max()),
+            ProgressBarText::Custom(label) => label.clone(),
+        }
+    }
+}
+
+/// Marks the label child spawned by [`progress_bar_text_system`]
+#[derive(Component, Debug, Default, Clone, Copy, PartialEq, Eq, Reflect)]
+#[reflect(Component)]
+pub struct ProgressBarLabel;
+
+/// Spawns a child [`Text`] node the first time a [`ProgressBarText`] is seen, and keeps its
+/// value in sync whenever [`Progress`] or [`ProgressBarText`] changes. The label is stretched
+/// over the whole bar with `position_type: Absolute` and centers its text with
+/// [`VerticalAlign::Center`]/[`HorizontalAlign::Center`], so it stays centered regardless of
+/// the bar's size.
+pub fn progress_bar_text_system(
+    mut commands: Commands,
+    bars: Query<
+        (Entity, &Progress, &ProgressBarText, Option<&Children>),
+        Or<(Changed<Progress>, Changed<ProgressBarText>)>,
+    >,
+    mut labels: Query<&mut Text, With<ProgressBarLabel>>,
+) {
+    for (entity, progress, text, children) in bars.iter() {
+        let value = text.format(progress);
+        let existing_label = children
+            .into_iter()
+            .flat_map(|children| children.iter())
+            .find_map(|&child| labels.get_mut(child).ok());
+
+        if let Some(mut label) = existing_label {
+            label.sections[0].value = value;
+        } else {
+            commands.entity(entity).with_children(|parent| {
+                let mut style = Style {
+                    position_type: PositionType::Absolute,
+                    size: Size::new(Val::Percent(100.0), Val::Percent(100.0)),
+                    ..Default::default()
+                };
+                style.position.left = Val::Percent(0.0);
+                style.position.top = Val::Percent(0.0);
+
+                parent
+                    .spawn_bundle(TextBundle {
+                        style,
+                        text: Text::with_section(
+                            value,
+                            TextStyle::default(),
+                            TextAlignment {
+                                vertical: VerticalAlign::Center,
+                                horizontal: HorizontalAlign::Center,
+                            },
+                        ),
+                        ..Default::default()
+                    })
+                    .insert(ProgressBarLabel);
+            });
+        }
+    }
+}
+
+/// Marks a progress bar as indeterminate: instead of tracking [`Progress`], a fixed-width
+/// fill sweeps back and forth across the track, for tasks with no known duration (loading
+/// assets, awaiting a network response, ...)
+#[derive(Component, Debug, Clone, Copy, PartialEq, Reflect)]
+#[reflect(Component)]
+pub struct ProgressBarIndeterminate {
+    /// Fraction of the track the sweeping fill covers along the axis [`ProgressBarAnimation`]
+    /// resizes (width for `ResizeWidth`, height for `ResizeHeight`), e.g. `0.25` for a
+    /// quarter-length fill
+    pub width: f32,
+    /// How much [`phase`](Self::phase) advances per second; one full there-and-back sweep
+    /// spans `2.0` of phase, so the fill completes `speed / 2.0` sweeps per second
+    pub speed: f32,
+    /// Animation phase in `0.0..=2.0`, advanced each frame by
+    /// [`progress_bar_indeterminate_system`]/[`progress_bar_indeterminate_foreground_system`]
+    pub phase: f32,
+}
+
+impl Default for ProgressBarIndeterminate {
+    fn default() -> Self {
+        ProgressBarIndeterminate {
+            width: 0.25,
+            speed: 0.5,
+            phase: 0.0,
+        }
+    }
+}
+
+/// Turns a phase in `0.0..=2.0` into a `0.0..=1.0` ping-pong offset for the leading edge of
+/// the sweeping fill
+fn indeterminate_offset(phase: f32, width: f32) -> f32 {
+    let triangle = if phase <= 1.0 { phase } else { 2.0 - phase };
+    triangle * (1.0 - width).max(0.0)
+}
+
+/// Resizes and positions an always-[`PositionType::Absolute`] node (e.g.
+/// [`ProgressBarForeground`]) along the axis/edge [`ProgressBarAnimation`]/
+/// [`ProgressBarDirection`] say the fill should sweep along, to `width` fraction of the
+/// track with its leading edge at `offset`. Only ever called on a node whose `position_type`
+/// is permanently `Absolute`, set once at spawn.
+fn sweep_absolute(
+    style: &mut Style,
+    dimension: ProgressBarAnimation,
+    direction: ProgressBarDirection,
+    width: f32,
+    offset: f32,
+) {
+    let (resize_width, resize_height) = match dimension {
+        ProgressBarAnimation::ResizeWidth => (true, false),
+        ProgressBarAnimation::ResizeHeight => (false, true),
+        ProgressBarAnimation::ResizeBothDimensions => (true, true),
+        ProgressBarAnimation::Custom => (false, false),
+    };
+    let reversed = is_reversed(direction);
+    if resize_width {
+        style.size.width = Val::Percent(width * 100.0);
+        if reversed {
+            style.position.left = Val::Undefined;
+            style.position.right = Val::Percent(offset * 100.0);
+        } else {
+            style.position.right = Val::Undefined;
+            style.position.left = Val::Percent(offset * 100.0);
+        }
+    }
+    if resize_height {
+        style.size.height = Val::Percent(width * 100.0);
+        if reversed {
+            style.position.top = Val::Undefined;
+            style.position.bottom = Val::Percent(offset * 100.0);
+        } else {
+            style.position.bottom = Val::Undefined;
+            style.position.top = Val::Percent(offset * 100.0);
+        }
+    }
+}
+
+/// Resizes and slides a node along the axis/edge [`ProgressBarAnimation`]/
+/// [`ProgressBarDirection`] say the fill should sweep along, to `width` fraction of the track
+/// with its leading edge at `offset`, using a margin offset instead of absolute positioning
+/// so the node's own [`Style::position_type`] — and therefore its membership in its parent's
+/// flow — is never touched. For [`ProgressBarAnimation::Custom`] this resizes/moves nothing,
+/// matching that variant's contract.
+fn sweep_in_flow(
+    style: &mut Style,
+    dimension: ProgressBarAnimation,
+    direction: ProgressBarDirection,
+    width: f32,
+    offset: f32,
+) {
+    let (resize_width, resize_height) = match dimension {
+        ProgressBarAnimation::ResizeWidth => (true, false),
+        ProgressBarAnimation::ResizeHeight => (false, true),
+        ProgressBarAnimation::ResizeBothDimensions => (true, true),
+        ProgressBarAnimation::Custom => (false, false),
+    };
+    let reversed = is_reversed(direction);
+    if resize_width {
+        style.size.width = Val::Percent(width * 100.0);
+        if reversed {
+            style.margin.left = Val::Undefined;
+            style.margin.right = Val::Percent(offset * 100.0);
+        } else {
+            style.margin.right = Val::Undefined;
+            style.margin.left = Val::Percent(offset * 100.0);
+        }
+    }
+    if resize_height {
+        style.size.height = Val::Percent(width * 100.0);
+        if reversed {
+            style.margin.top = Val::Undefined;
+            style.margin.bottom = Val::Percent(offset * 100.0);
+        } else {
+            style.margin.bottom = Val::Undefined;
+            style.margin.top = Val::Percent(offset * 100.0);
+        }
+    }
+}
+
+/// Advances the sweep animation for indeterminate bars with no [`ProgressBarStyle`], resizing
+/// and sliding the bar itself along the axis/edge its [`ProgressBarAnimation`]/
+/// [`ProgressBarDirection`] say it should fill along. Never touches the bar's own
+/// [`Style::position_type`], so it keeps its caller-assigned place in its parent's flow, and
+/// a [`ProgressBarAnimation::Custom`] bar is left exactly as the caller's own system put it.
+pub fn progress_bar_indeterminate_system(
+    time: Res<Time>,
+    mut bars: Query<
+        (
+            &mut ProgressBarIndeterminate,
+            Option<&ProgressBarAnimation>,
+            Option<&ProgressBarDirection>,
+            &mut Style,
+        ),
+        Without<ProgressBarStyle>,
+    >,
+) {
+    let delta = time.delta_seconds();
+    for (mut indeterminate, dimension, direction, mut style) in bars.iter_mut() {
+        indeterminate.phase = (indeterminate.phase + indeterminate.speed * delta).rem_euclid(2.0);
+        let offset = indeterminate_offset(indeterminate.phase, indeterminate.width);
+        sweep_in_flow(
+            &mut style,
+            dimension.copied().unwrap_or_default(),
+            direction.copied().unwrap_or_default(),
+            indeterminate.width,
+            offset,
+        );
+    }
+}
+
+/// Advances the sweep animation for indeterminate bars using a [`ProgressBarStyle`], resizing
+/// and repositioning the foreground child (along the axis/edge its [`ProgressBarAnimation`]/
+/// [`ProgressBarDirection`] say it should fill along) while the background stays full-size
+pub fn progress_bar_indeterminate_foreground_system(
+    time: Res<Time>,
+    mut bars: Query<
+        (
+            &mut ProgressBarIndeterminate,
+            Option<&ProgressBarAnimation>,
+            Option<&ProgressBarDirection>,
+            &Children,
+        ),
+        With<ProgressBarStyle>,
+    >,
+    mut foregrounds: Query<&mut Style, With<ProgressBarForeground>>,
+) {
+    let delta = time.delta_seconds();
+    for (mut indeterminate, dimension, direction, children) in bars.iter_mut() {
+        indeterminate.phase = (indeterminate.phase + indeterminate.speed * delta).rem_euclid(2.0);
+        let offset = indeterminate_offset(indeterminate.phase, indeterminate.width);
+        let dimension = dimension.copied().unwrap_or_default();
+        let direction = direction.copied().unwrap_or_default();
+        for &child in children.iter() {
+            if let Ok(mut style) = foregrounds.get_mut(child) {
+                sweep_absolute(&mut style, dimension, direction, indeterminate.width, offset);
+            }
         }
     }
 }